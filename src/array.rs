@@ -1,10 +1,11 @@
 
 use {
+    crate::error::ReserveError,
     std::{
         io::{Error, ErrorKind,},
-        ptr::{read, write, copy_nonoverlapping, copy},
+        ptr::{read, write, copy_nonoverlapping, copy, drop_in_place},
         alloc::{alloc_zeroed, dealloc, Layout},
-        mem::{size_of},
+        mem::{size_of, align_of, forget},
         fmt::{Display, Debug, Formatter, Result as fmt_Result},
     },
 };
@@ -17,6 +18,11 @@ pub struct Array<T> {
 
     /// Creates a new array of type T with size size
     pub fn new(size: usize) -> Result<Self, Error> {
+        // zero-sized types (like `()`) don't need any actual memory, and `alloc_zeroed` with
+        // a 0-byte layout is UB anyway, so just skip the allocator entirely for these
+        if Self::is_zst() {
+            return Ok(Array { ptr: Self::dangling(), size });
+        }
         unsafe {
             let ptr = alloc_zeroed(Self::layout_for_size(size)?) as *mut T;
             Ok(Array {
@@ -41,6 +47,17 @@ pub struct Array<T> {
         }}
     }
 
+    /// Borrows the value at the index instead of reading it out; `get` duplicates it via
+    /// `ptr::read`, which is fine once but double-frees a `T` with a real `Drop` if that same
+    /// slot ever gets read a second time before being overwritten
+    pub fn get_ref(&self, index: usize) -> Result<&T, Error> {
+        if index >= self.size {
+            Err(Error::new(ErrorKind::Other, format!("ArrayErrNo 7: index {} out of range (0 -> {})", index, self.size - 1)))
+        } else {
+            Ok(unsafe { &*self.as_raw_ptr().add(index) })
+        }
+    }
+
     pub fn get_slice(&self, start: usize, stop: usize, step: usize) -> Result<Self, Error> {
         // If stop is greater than the size of the array, fix that
         
@@ -77,6 +94,8 @@ pub struct Array<T> {
 
     /// clears the array; sets all the values to 0x00
     pub fn clear(&mut self) -> Result<(), Error> {
+        // nothing allocated for a ZST, so nothing to zero out
+        if Self::is_zst() { return Ok(()); }
         unsafe {
             let new_ptr = alloc_zeroed(Self::layout_for_size(self.size)?) as *mut T;
             dealloc(self.ptr as *mut u8, Self::layout_for_size(self.size)?);
@@ -89,13 +108,18 @@ pub struct Array<T> {
     /// Resizes the current array, if the array grows, it will put zeroes in the newly allocated memory,
     /// if the array shrinks, it will delete the values outside of the previously allocated memory
     pub fn resize(&mut self, new_size: usize) -> Result<(), Error> {
+        // again, ZST = no allocation, so resizing is just updating the number
+        if Self::is_zst() {
+            self.size = new_size;
+            return Ok(());
+        }
         unsafe {
 
             // allocate space for the new array
             let new_ptr = alloc_zeroed(Self::layout_for_size(new_size)?) as *mut T;
             // copy the data from the current array to the new array
-            // use the smaller of the two sizes to copy over 
-            copy_nonoverlapping(self.as_raw_ptr(), new_ptr, { if new_size < self.size { new_size } else { self.size } });
+            // use the smaller of the two sizes to copy over
+            copy_nonoverlapping(self.as_raw_ptr(), new_ptr, if new_size < self.size { new_size } else { self.size });
             // deallocate the current array
             dealloc(self.ptr as *mut u8, Self::layout_for_size(self.size)?);
 
@@ -118,6 +142,8 @@ pub struct Array<T> {
     }
 
     pub fn shift_from(&mut self, index: usize, amt: isize) -> Result<(), Error> {
+        // nothing to move around for a ZST, the indices are all the caller's problem anyway
+        if Self::is_zst() { return Ok(()); }
         // Copy the data from self to a buffer
         let buf = self.clone();
         // Clear out self's data
@@ -127,7 +153,7 @@ pub struct Array<T> {
             copy_nonoverlapping(buf.as_raw_ptr(), self.as_mut_raw_ptr(), index);
             
             // Write the data from behind the index + the amount to shift by (leaving a gap or overwriting the data that's there)
-            copy_nonoverlapping(buf.as_raw_ptr().add(index), self.as_mut_raw_ptr().add({(index as isize + amt) as usize}), buf.size - index);
+            copy_nonoverlapping(buf.as_raw_ptr().add(index), self.as_mut_raw_ptr().add((index as isize + amt) as usize), buf.size - index);
         }
         Ok(())
     }
@@ -138,12 +164,17 @@ pub struct Array<T> {
         } else if index == 0 {
             (0, self.size)
         } else {
-            (index, self.size - index) 
+            (index, self.size - index)
         };
 
+        // ZST again, nothing to actually move, the two halves just need their sizes set right
+        if Self::is_zst() {
+            return Ok((Self::new(lsize)?, Self::new(rsize)?));
+        }
+
         let mut l = Self::new(lsize)?;
         let mut r = Self::new(rsize)?;
-        
+
         self.get_slice(0, l.size, 1)?.clone_into(&mut l)?;
         self.get_slice(l.size, self.size, 1)?.clone_into(&mut r)?;
 
@@ -194,24 +225,56 @@ impl<T> Array<T> {
         // set align to the size of T if it's a power of two (u32, u8, etc), otherwise, set it to the next power of two
         let align = if size_of_t.is_power_of_two() { size_of_t } else { size_of_t.next_power_of_two() };
 
+        // size * size_of_t can itself overflow usize before it ever reaches Layout::from_size_align, so check first
+        let bytes = match size.checked_mul(size_of_t) {
+            Some(n) => n,
+            None => return Err(Error::new(ErrorKind::Other, format!("ArrayErrNo 6: {} * {} overflows usize", size, size_of_t))),
+        };
+
         // use the checked from_size_align to make sure the values are correct, return an error if it does
         // ya know, just in case
-        // size is the amount of elements in the array, size_of_t is the size of each element
-        match Layout::from_size_align(size * size_of_t, align) {
+        match Layout::from_size_align(bytes, align) {
             Ok(n) => Ok(n),
             // This (((THEORETICALLY))) isn't reachable
             Err(_) => Err(Error::new(ErrorKind::Other, format!("ArrayErrNo 5: Unable to create Layout from {{ align: {}, size: {} }}", align, size)))
         }
     }
 
+    /// Like `new`, but surfaces a `ReserveError` instead of silently proceeding on a null
+    /// allocator return. Used by `List::reserve`/`reserve_exact`, which need the real
+    /// fallible-allocation contract rather than `new`'s "this theoretically can't fail" one.
+    pub(crate) fn try_new(size: usize) -> Result<Self, ReserveError> {
+        if Self::is_zst() {
+            return Ok(Array { ptr: Self::dangling(), size });
+        }
+        let layout = Self::layout_for_size(size).map_err(|_| ReserveError::CapacityOverflow)?;
+        unsafe {
+            let ptr = alloc_zeroed(layout) as *mut T;
+            if ptr.is_null() {
+                return Err(ReserveError::AllocError { layout });
+            }
+            Ok(Array { ptr, size })
+        }
+    }
 
-    fn as_mut_raw_ptr(&mut self) -> *mut T {
+    pub(crate) fn as_mut_raw_ptr(&mut self) -> *mut T {
         self.ptr as *mut T
     }
 
-    fn as_raw_ptr(&self) -> *const T {
+    pub(crate) fn as_raw_ptr(&self) -> *const T {
         self.ptr as *const T
     }
+
+    /// true if T is zero-sized, meaning we never actually need to allocate for it
+    fn is_zst() -> bool {
+        size_of::<T>() == 0
+    }
+
+    /// a pointer to nowhere in particular, but aligned right for T; that's all a ZST array
+    /// needs instead of a real allocation (same trick Vec does under the hood)
+    fn dangling() -> *mut T {
+        align_of::<T>() as *mut T
+    }
 }
 
 // Trait Implementations
@@ -242,6 +305,8 @@ impl<T: Display> Debug for Array<T> {
     }
 } impl<T> Drop for Array<T> {
     fn drop(&mut self) {
+        // never allocated it for a ZST, so there's nothing to give back here
+        if Self::is_zst() { return; }
         // eprintln!("Dropping Array at {:p}", self.ptr);
         unsafe { dealloc(self.ptr as *mut u8, Self::layout_for_size(self.size).unwrap()); }
     }
@@ -250,29 +315,63 @@ impl<T: Display> Debug for Array<T> {
     type IntoIter = ArrayIter<Self::Item>;
     
     fn into_iter(self) -> Self::IntoIter {
-        ArrayIter::new(self.as_raw_ptr(), self.size )
+        let iter = ArrayIter::new(self.as_raw_ptr(), self.size);
+        // ArrayIter reads straight out of this same buffer, so self's Drop better not run -
+        // that'd dealloc it right out from under the iterator. forget() hands ownership off instead
+        forget(self);
+        iter
     }
 }
 
 pub struct ArrayIter<T> {
     arr: *const T,
+    // the size the buffer was originally allocated at; front/back only track what's left to
+    // yield, but dealloc needs the layout it was actually alloc'd with
     size: usize,
-    cur: usize,
+    front: usize,
+    back: usize,
 } impl<T> ArrayIter<T> {
     fn new(arr: *const T, size: usize) -> Self {
         ArrayIter {
-            arr, size, cur: 0usize,
+            arr, size, front: 0usize, back: size,
         }
     }
 } impl<T> Iterator for ArrayIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.cur += 1;
-        if self.cur == self.size {
+        if self.front >= self.back {
             None
         } else { unsafe {
-            Some(read(self.arr.add(self.cur-1)))
+            let v = read(self.arr.add(self.front));
+            self.front += 1;
+            Some(v)
         }}
     }
+} impl<T> DoubleEndedIterator for ArrayIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(unsafe { read(self.arr.add(self.back)) })
+        }
+    }
+} impl<T> ExactSizeIterator for ArrayIter<T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+} impl<T> Drop for ArrayIter<T> {
+    fn drop(&mut self) {
+        // we own everything from front..back still (already-yielded stuff was moved out via
+        // read() above and is the caller's problem now) - drop whatever's left, then free
+        // the buffer itself, same as Array::drop does
+        unsafe {
+            for i in self.front..self.back {
+                drop_in_place(self.arr.add(i) as *mut T);
+            }
+        }
+        if Array::<T>::is_zst() { return; }
+        unsafe { dealloc(self.arr as *mut u8, Array::<T>::layout_for_size(self.size).unwrap()); }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::{
+    alloc::Layout,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as fmt_Result},
+    io::Error,
+};
+
+/// What can go wrong when List<T> tries to grow itself (reserve/reserve_exact). Everything
+/// else in this crate just shoves errors into a plain io::Error, but here we actually care
+/// whether it was "you asked for too much" vs "the allocator said no", so it gets its own type
+#[derive(Debug)]
+pub enum ReserveError {
+    /// you (or the math) asked for more elements/bytes than usize can hold
+    CapacityOverflow,
+    /// malloc (well, alloc_zeroed) gave us back a null pointer for `layout`
+    AllocError { layout: Layout },
+}
+
+impl Display for ReserveError {
+    fn fmt(&self, f: &mut Formatter) -> fmt_Result {
+        match self {
+            ReserveError::CapacityOverflow => write!(f, "ReserveError: capacity overflow"),
+            ReserveError::AllocError { layout } => write!(f, "ReserveError: allocator returned null for layout {{ size: {}, align: {} }}", layout.size(), layout.align()),
+        }
+    }
+}
+
+impl StdError for ReserveError {}
+
+// so reserve/reserve_exact's `?` still works alongside the rest of the crate's io::Error stuff
+impl From<ReserveError> for Error {
+    fn from(e: ReserveError) -> Self {
+        Error::other(e.to_string())
+    }
+}
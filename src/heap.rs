@@ -0,0 +1,121 @@
+use {
+    crate::list::List,
+    std::io::{Error, ErrorKind},
+};
+
+/// Max-heap priority queue, just List<T> underneath with push/pop done in O(log n) instead
+/// of List<T>'s O(1)-but-unordered push_back. The usual binary-tree-crammed-into-an-array
+/// trick: index `i`'s kids live at `2i+1`/`2i+2`, and its parent's at `(i-1)/2`.
+pub struct Heap<T: Ord> {
+    list: List<T>,
+}
+
+// Public methods
+impl<T: Ord> Heap<T> {
+    /// Tries to make a new, empty Heap<T>
+    pub fn new() -> Result<Self, Error> {
+        Ok(Heap { list: List::new()? })
+    }
+
+    /// How many elements are in the Heap<T>
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Peeks at the biggest element without popping it
+    pub fn peek(&self) -> Result<&T, Error> {
+        self.list.get_ref(0)
+    }
+
+    /// Pushes `value` on, then bubbles it up toward the root while it's bigger than its parent
+    pub fn push(&mut self, value: T) -> Result<(), Error> {
+        self.list.push_back(value)?;
+        self.sift_up(self.list.len() - 1)
+    }
+
+    /// Pops the biggest element off. Swaps root with the last element, chops the old root off
+    /// the back, then sifts the new root back down to where it belongs
+    pub fn pop(&mut self) -> Result<T, Error> {
+        if self.list.len() == 0 { return Err(Error::new(ErrorKind::Other, "Heap empty")); }
+
+        let last = self.list.len() - 1;
+        self.swap(0, last)?;
+        let old_root = self.list.pop_back()?;
+
+        if self.list.len() > 0 {
+            self.sift_down(0)?;
+        }
+        Ok(old_root)
+    }
+
+    /// Drains the whole heap out into a List<T>, smallest first
+    pub fn into_sorted(mut self) -> Result<List<T>, Error> {
+        let mut out = List::with_capacity(self.list.len())?;
+        while self.list.len() > 0 {
+            // pop always gives back whatever's currently biggest, so pushing each one to
+            // the front as we go leaves us sorted ascending once the heap's empty
+            let value = self.pop()?;
+            out.push_front(value)?;
+        }
+        Ok(out)
+    }
+}
+
+// Private methods
+impl<T: Ord> Heap<T> {
+    /// Swaps two slots. Don't be tempted to do this with List::get/set instead - that reads
+    /// the value out instead of borrowing it, and double-frees the second time a sift touches
+    /// the same slot (ask me how I know). See List::get_ref's comment for the full story.
+    fn swap(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        self.list.swap(i, j)
+    }
+
+    fn sift_up(&mut self, mut i: usize) -> Result<(), Error> {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.list.get_ref(i)? > self.list.get_ref(parent)? {
+                self.swap(i, parent)?;
+                i = parent;
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn sift_down(&mut self, mut i: usize) -> Result<(), Error> {
+        let len = self.list.len();
+        loop {
+            let left = 2 * i + 1;
+            let right = 2 * i + 2;
+            let mut largest = i;
+
+            if left < len && self.list.get_ref(left)? > self.list.get_ref(largest)? { largest = left; }
+            if right < len && self.list.get_ref(right)? > self.list.get_ref(largest)? { largest = right; }
+
+            if largest == i { break; }
+            self.swap(i, largest)?;
+            i = largest;
+        }
+        Ok(())
+    }
+}
+
+// Trait implementations
+impl<T: Ord> From<List<T>> for Heap<T> {
+    fn from(list: List<T>) -> Self {
+        // classic Floyd build-heap - sift down every non-leaf starting from the last one, working up to the root
+        let mut heap = Heap { list };
+        let len = heap.list.len();
+        if len > 1 {
+            for i in (0..len / 2).rev() {
+                heap.sift_down(i).unwrap();
+            }
+        }
+        heap
+    }
+} impl<T: Ord + Clone> From<Vec<T>> for Heap<T> {
+    fn from(v: Vec<T>) -> Self {
+        Heap::from(List::from(v))
+    }
+}
@@ -1,11 +1,19 @@
-mod array;
-mod list;
+pub mod array;
+pub mod error;
+pub mod heap;
+pub mod list;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod small_list;
 #[cfg(test)]
 mod tests {
     use {
         crate::{
             array::Array,
+            error::ReserveError,
+            heap::Heap,
             list::List,
+            small_list::SmallList,
         },
         std::{
             io::{Error},
@@ -37,4 +45,204 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn ring_buffer_push_pop_both_ends() -> Result<(), Error> {
+        // push_front and push_back both write into the same backing Array<T>, wrapping
+        // around its ends, so make sure popping gives values back out in the right order
+        let mut l: List<i32> = List::new()?;
+        l.push_back(1)?;
+        l.push_back(2)?;
+        l.push_front(0)?;
+        l.push_front(-1)?;
+        // list is now [-1, 0, 1, 2]
+        assert_eq!(l.len(), 4);
+        assert_eq!(l.get(0)?, -1);
+        assert_eq!(l.get(3)?, 2);
+
+        assert_eq!(l.pop_front()?, -1);
+        assert_eq!(l.pop_back()?, 2);
+        assert_eq!(l.pop_front()?, 0);
+        assert_eq!(l.pop_back()?, 1);
+        assert_eq!(l.len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn zst_list_never_allocates_but_still_counts() -> Result<(), Error> {
+        // () is a zero-sized type, so Array<()> skips alloc_zeroed entirely; make sure the
+        // List<T> on top of it still tracks length/indexing correctly despite that
+        let mut l: List<()> = List::new()?;
+        for _ in 0..8 {
+            l.push_back(())?;
+        }
+        assert_eq!(l.len(), 8);
+        assert_eq!(l.get(3)?, ());
+        assert_eq!(l.pop_front()?, ());
+        assert_eq!(l.len(), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn reserve_grows_capacity_without_losing_elements() -> Result<(), ReserveError> {
+        let mut l: List<i32> = List::new().unwrap();
+        l.push_back(1).unwrap();
+        l.push_back(2).unwrap();
+
+        l.reserve(100)?;
+        // reserve must never shrink existing contents, only ever grow room for more
+        assert_eq!(l.len(), 2);
+        assert_eq!(l.get(0).unwrap(), 1);
+        assert_eq!(l.get(1).unwrap(), 2);
+
+        // there's already room for 5 more after reserve(100), so reserve_exact leaves things alone
+        l.reserve_exact(5)?;
+        assert_eq!(l.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn reserve_reports_capacity_overflow_instead_of_panicking() {
+        let mut l: List<u8> = List::new().unwrap();
+        let err = l.reserve(usize::MAX).unwrap_err();
+        assert!(matches!(err, ReserveError::CapacityOverflow));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn list_serde_round_trip() -> Result<(), Error> {
+        let mut l: List<i32> = List::new()?;
+        l.push_back(1)?;
+        l.push_back(2)?;
+        l.push_back(3)?;
+
+        let json = serde_json::to_string(&l).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let back: List<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.len(), 3);
+        assert_eq!(back.get(1)?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn small_list_spills_past_inline_capacity() -> Result<(), Error> {
+        let mut l: SmallList<i32, 2> = SmallList::new();
+        l.push_back(1)?;
+        l.push_back(2)?;
+        assert_eq!(l.len(), 2);
+
+        // a third element overflows the inline [MaybeUninit<T>; 2] storage, forcing a spill
+        // to a heap-backed List<T>; everything already pushed should come along for the ride
+        l.push_back(3)?;
+        assert_eq!(l.len(), 3);
+        assert_eq!(l.get(0)?, 1);
+        assert_eq!(l.get(1)?, 2);
+        assert_eq!(l.get(2)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn array_into_iter_yields_every_element() -> Result<(), Error> {
+        // ArrayIter used to increment its cursor before comparing against size, dropping the
+        // last element; from_iter/into_iter round-tripping all of them is the regression test
+        let arr: Array<i32> = Array::from_iter(vec![1, 2, 3].into_iter())?;
+        let collected: Vec<i32> = arr.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn list_iter_borrows_and_supports_double_ended() -> Result<(), Error> {
+        let mut l: List<i32> = List::new()?;
+        l.push_back(1)?;
+        l.push_back(2)?;
+        l.push_back(3)?;
+
+        // iter() borrows, so l must still be usable afterward
+        let front_to_back: Vec<i32> = l.iter().copied().collect();
+        assert_eq!(front_to_back, vec![1, 2, 3]);
+
+        let mut rev = l.iter();
+        assert_eq!(rev.next_back(), Some(&3));
+        assert_eq!(rev.next(), Some(&1));
+
+        assert_eq!(l.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn chunks_and_array_chunks_split_evenly_with_a_remainder() -> Result<(), Error> {
+        let l: List<i32> = List::from_iter(1..=5)?;
+
+        let chunks: Vec<Vec<i32>> = l.chunks(2)
+            .map(|c| c.into_iter().collect())
+            .collect();
+        assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+        let mut array_chunks = l.array_chunks::<2>();
+        assert_eq!(array_chunks.next(), Some([1, 2]));
+        assert_eq!(array_chunks.next(), Some([3, 4]));
+        assert_eq!(array_chunks.next(), None);
+        // the 5th element didn't fit in a full [i32; 2], so it's left for remainder()
+        let remainder: Vec<i32> = array_chunks.remainder()?.into_iter().collect();
+        assert_eq!(remainder, vec![5]);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_of_zero_panics_instead_of_spinning_forever() {
+        let l: List<i32> = List::from_iter(1..=5).unwrap();
+        l.chunks(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn array_chunks_of_zero_panics_instead_of_spinning_forever() {
+        let l: List<i32> = List::from_iter(1..=5).unwrap();
+        l.array_chunks::<0>();
+    }
+
+    #[test]
+    fn heap_pops_in_descending_order() -> Result<(), Error> {
+        let mut h: Heap<i32> = Heap::new()?;
+        for v in [5, 1, 4, 2, 3] {
+            h.push(v)?;
+        }
+        assert_eq!(h.len(), 5);
+        assert_eq!(*h.peek()?, 5);
+
+        let mut popped = Vec::new();
+        while h.len() > 0 {
+            popped.push(h.pop()?);
+        }
+        assert_eq!(popped, vec![5, 4, 3, 2, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn heap_sift_never_double_frees_non_copy_elements() -> Result<(), Error> {
+        // regression test: sifting used to read elements out by value instead of borrowing
+        // them, which double-freed owned types like String the moment a slot was compared
+        // or swapped more than once
+        let mut h: Heap<String> = Heap::new()?;
+        for s in ["banana", "apple", "cherry", "date", "fig"] {
+            h.push(s.to_string())?;
+        }
+        let mut popped = Vec::new();
+        while h.len() > 0 {
+            popped.push(h.pop()?);
+        }
+        assert_eq!(popped, vec!["fig", "date", "cherry", "banana", "apple"]);
+        Ok(())
+    }
+
+    #[test]
+    fn heap_from_vec_builds_then_sorts() -> Result<(), Error> {
+        let h: Heap<i32> = Heap::from(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+        let sorted: Vec<i32> = h.into_sorted()?.into_iter().collect();
+        assert_eq!(sorted, vec![1, 1, 2, 3, 4, 5, 6, 9]);
+        Ok(())
+    }
 }
@@ -1,21 +1,29 @@
 use {
-    crate::array::Array,
+    crate::{array::Array, error::ReserveError},
     std::{
-        io::{Error, ErrorKind},
+        io::{Error},
+        ptr::{copy_nonoverlapping, drop_in_place},
+        marker::PhantomData,
+        mem::MaybeUninit,
         fmt::{Display, Debug, Formatter, Result as fmt_Result},
-        
+
     },
 };
 
 
 /// arr: Array<T>,
 ///   the crate::array:::Array<T> that the List<T> wraps
+/// head: usize,
+///   where logical index 0 actually lives in arr right now, since this is a ring buffer
+///   and stuff wraps around. logical `i` is physically at `(head + i) & (cap - 1)`
 /// len: usize,
 ///   current number of values in the List<T>
 /// cap: usize,
 ///   max number of values the List<T> can hold before it needs to grow
+///   (gotta stay a power of two or the `& (cap - 1)` trick stops working)
 pub struct List<T> {
     arr: Array<T>,
+    head: usize,
     len: usize,
     cap: usize,
 }
@@ -26,14 +34,17 @@ impl<T> List<T> {
     pub fn new() -> Result<Self, Error> {
         Ok(List {
             arr: Array::<T>::new(4)?,
+            head: 0,
             len: 0,
             cap: 4,
         })
     }
 
     pub fn with_capacity(cap: usize) -> Result<Self, Error> {
+        let cap = Self::round_cap(cap);
         Ok(List{
             arr: Array::<T>::new(cap)?,
+            head: 0,
             len: 0,
             cap,
         })
@@ -41,29 +52,21 @@ impl<T> List<T> {
 
     /// Creates a new list from an iterator
     pub fn from_iter<U: Iterator<Item=T>>(i: U) -> Result<Self, Error> where T: Clone {
-
-        let (len, i) = {
-            let v: Vec<T> = i.collect();
-            // get the length of the iterator
-            let len = v.len();
-            // recreate the iterator
-            let i = v.into_iter();
-            (len, i)
-        };
-        Ok(List {
-            arr: Array::<T>::from_iter(i)?,
-            len: len,
-            cap: len,
-        })
+        let v: Vec<T> = i.collect();
+        let mut list = Self::with_capacity(v.len())?;
+        for item in v {
+            list.push_back(item)?;
+        }
+        Ok(list)
     }
 
     /// Gets the value at `index`
     pub fn get(&self, index: usize) -> Result<T, Error> {
         // if the index is out of range, return an error saying that the index is out of range, do i need to explain this to you?
         if index >= self.len {
-            Err(Error::new(ErrorKind::Other, "Index out of range"))
+            Err(Error::other("Index out of range"))
         } else {
-            Ok(self.arr.get(index)?)
+            Ok(self.arr.get(self.physical(index))?)
         }
     }
 
@@ -72,19 +75,63 @@ impl<T> List<T> {
         self.len
     }
 
-    /// Pushes `value` to the front of the List<T> 
+    /// Sets the value at `index` to `value`, returning the previous value
+    pub fn set(&mut self, index: usize, value: T) -> Result<T, Error> {
+        if index >= self.len {
+            Err(Error::other("Index out of range"))
+        } else {
+            Ok(self.arr.set(self.physical(index), value)?)
+        }
+    }
+
+    /// Borrows the value at `index` instead of reading it out. `get` does a `ptr::read` under
+    /// the hood (same as Array::get) which duplicates the value - fine if you only touch that
+    /// slot once, but a double-free waiting to happen for anything that needs to look at or
+    /// compare a slot more than once, like Heap<T>'s sifting does
+    pub fn get_ref(&self, index: usize) -> Result<&T, Error> {
+        if index >= self.len {
+            Err(Error::other("Index out of range"))
+        } else {
+            let p = self.physical(index);
+            Ok(unsafe { &*self.arr.as_raw_ptr().add(p) })
+        }
+    }
+
+    /// Swaps the elements at logical indices `i` and `j` in place. Does it with a raw-pointer
+    /// swap instead of get-then-set, so neither element ever gets duplicated into a temporary
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        if i >= self.len || j >= self.len {
+            return Err(Error::other("Index out of range"));
+        }
+        if i == j { return Ok(()); }
+        let pi = self.physical(i);
+        let pj = self.physical(j);
+        unsafe {
+            std::ptr::swap(self.arr.as_mut_raw_ptr().add(pi), self.arr.as_mut_raw_ptr().add(pj));
+        }
+        Ok(())
+    }
+
+    /// Pushes `value` to the front of the List<T> in O(1) amortized time
     pub fn push_front(&mut self, value: T) -> Result<(), Error> {
-        self.insert(0, value)?;
+        self.reserve(1)?;
+        // the slot one behind head is always free (cap is kept strictly larger than len)
+        self.head = (self.head + self.cap - 1) & (self.cap - 1);
+        self.arr.set(self.head, value)?;
+        self.len += 1;
         Ok(())
     }
 
-    /// Pushes `value` to the back of the List<T> 
+    /// Pushes `value` to the back of the List<T> in O(1) amortized time
     pub fn push_back(&mut self, value: T) -> Result<(), Error> {
-        self.insert(self.len, value)?;
+        self.reserve(1)?;
+        let slot = self.physical(self.len);
+        self.arr.set(slot, value)?;
+        self.len += 1;
         Ok(())
     }
 
-    /// Pushes `value` to the index `index` in the List<T> 
+    /// Pushes `value` to the index `index` in the List<T>
     pub fn push(&mut self, index: usize, value: T) -> Result<(), Error> {
         self.insert(index, value)?;
         Ok(())
@@ -95,93 +142,222 @@ impl<T> List<T> {
         self.del(index)
     }
 
-    /// Pops and returns the value at the back of the List<T>
+    /// Pops and returns the value at the back of the List<T> in O(1) time
     pub fn pop_back(&mut self) -> Result<T, Error> {
-        self.del(self.len - 1)
+        if self.len == 0 { return Err(Error::other("List empty")); }
+
+        let lower_pow2 = self.cap / 2;
+        if self.len < lower_pow2 {
+            self.shrink()?;
+        }
+
+        let old = self.arr.get(self.physical(self.len - 1))?;
+        self.len -= 1;
+        Ok(old)
     }
-    
-    /// Pops and returns the value at the front of the List<T>
+
+    /// Pops and returns the value at the front of the List<T> in O(1) time
     pub fn pop_front(&mut self) -> Result<T, Error> {
-        self.del(0)
+        if self.len == 0 { return Err(Error::other("List empty")); }
+
+        let lower_pow2 = self.cap / 2;
+        if self.len < lower_pow2 {
+            self.shrink()?;
+        }
+
+        let old = self.arr.get(self.head)?;
+        self.head = (self.head + 1) & (self.cap - 1);
+        self.len -= 1;
+        Ok(old)
+    }
+
+    /// Makes sure there's room for at least `additional` more elements, doubling the capacity
+    /// like before if it has to grow (instead of allocating the exact amount). Actually checks
+    /// the math this time instead of just hoping it doesn't overflow.
+    pub fn reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        // +1 so growing always leaves one slot spare past `len` (full vs empty is told apart by `len` itself)
+        let required = self.len
+            .checked_add(additional)
+            .and_then(|n| n.checked_add(1))
+            .ok_or(ReserveError::CapacityOverflow)?;
+        if required <= self.cap { return Ok(()); }
+
+        let mut target = self.cap;
+        while target < required {
+            target = target.checked_mul(2).ok_or(ReserveError::CapacityOverflow)?;
+        }
+        self.try_resize_to(target)
+    }
+
+    /// Same as `reserve`, but doesn't double capacity past what's needed - just grows to the
+    /// smallest power of two (min 4) that fits `additional` more elements plus the one spare slot
+    pub fn reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+        let required = self.len
+            .checked_add(additional)
+            .and_then(|n| n.checked_add(1))
+            .ok_or(ReserveError::CapacityOverflow)?;
+        if required <= self.cap { return Ok(()); }
+
+        let target = Self::round_cap(required);
+        self.try_resize_to(target)
+    }
+
+    /// Borrows the List<T> as an iterator of &T, front to back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            ptr: self.arr.as_raw_ptr(),
+            head: self.head,
+            cap: self.cap,
+            front: 0,
+            back: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Same as iter() but &mut T, front to back
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            ptr: self.arr.as_mut_raw_ptr(),
+            head: self.head,
+            cap: self.cap,
+            front: 0,
+            back: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Hands back owned chunks of `n` elements at a time, last one shorter if `len` doesn't
+    /// divide evenly. They're owned List<T>s and not slices since the ring buffer wraps
+    /// around, so a chunk usually isn't sitting contiguously in memory anyway
+    pub fn chunks(&self, n: usize) -> Chunks<'_, T> {
+        // n == 0 would never advance pos, so next() would just spin forever handing back
+        // empty chunks - same contract std::slice::chunks has, so panic like it does
+        assert!(n != 0, "chunk size must be non-zero");
+        Chunks { list: self, n, pos: 0 }
+    }
+
+    /// Same idea but as [T; N] arrays. Leftover elements that don't fill a full N get
+    /// dropped from the iteration - grab them after with ArrayChunks::remainder
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, N> {
+        // same deal as chunks(0) - N == 0 would never advance pos and spin forever
+        assert!(N != 0, "chunk size must be non-zero");
+        ArrayChunks { list: self, pos: 0 }
     }
 
 }
 
 // Private methods
 impl<T> List<T> {
+    /// Turns a logical index into where it actually lives in the backing Array<T>
+    fn physical(&self, logical: usize) -> usize {
+        (self.head + logical) & (self.cap - 1)
+    }
+
+    /// Bumps a requested capacity up to a power of two, min 4, since `physical`'s wraparound
+    /// math needs that to work
+    fn round_cap(cap: usize) -> usize {
+        let cap = if cap < 4 { 4 } else { cap };
+        if cap.is_power_of_two() { cap } else { cap.next_power_of_two() }
+    }
+
     /// Backend for the push methods
-    /// inserts `value` at `index`
+    /// inserts `value` at `index`, shifting whichever side of the ring is shorter
     fn insert(&mut self, index: usize, value: T) -> Result<(), Error> {
         // if the index is out of bounds, return an error
-        if index > self.len() { return Err(Error::new(ErrorKind::Other, "Index out of bounds")); } 
-        // if the array is full
-        if self.len + 1 >= self.cap {
-            // grow the array
-            self.grow()?;
+        if index > self.len() { return Err(Error::other("Index out of bounds")); }
+        // make sure there's room, growing the array if not
+        self.reserve(1)?;
+
+        if index <= self.len / 2 {
+            // closer to the front: free up a slot behind head and shift 0..index back into it
+            let old_head = self.head;
+            self.head = (self.head + self.cap - 1) & (self.cap - 1);
+            for i in 0..index {
+                let v = self.arr.get((old_head + i) & (self.cap - 1))?;
+                self.arr.set((old_head + i + self.cap - 1) & (self.cap - 1), v)?;
+            }
+        } else {
+            // closer to the back: shift index..len forward by one to open a gap at index
+            for i in (index..self.len).rev() {
+                let v = self.arr.get(self.physical(i))?;
+                self.arr.set(self.physical(i + 1), v)?;
+            }
         }
-
-        // shift from the index to the right one
-        self.arr.shift_from(index, 1)?;
-        // insert the value at the new opening
-        self.arr.set(index, value)?;
+        self.arr.set(self.physical(index), value)?;
         self.len += 1;
         return Ok(());
     }
 
     /// Backend for the pop methods
-    /// removes the item at `index` and returns it
+    /// removes the item at `index` and returns it, shifting whichever side of the ring is shorter
     fn del(&mut self, index: usize) -> Result<T, Error> {
         // if the index is out of bounds, return an error
-        if index > self.len() { return Err(Error::new(ErrorKind::Other, "Index out of bounds")); } 
+        if index >= self.len() { return Err(Error::other("Index out of bounds")); }
 
         // if the array is empty, return an error
-        if self.len == 0 { return Err(Error::new(ErrorKind::Other, "List empty")); }
+        if self.len == 0 { return Err(Error::other("List empty")); }
 
         // if the array should be shrunkened
-        let lower_pow2 = if self.cap.is_power_of_two() { self.cap / 2 } else { self.cap.next_power_of_two() / 2 };
+        let lower_pow2 = self.cap / 2;
         if self.len < lower_pow2 {
             // shrink it
             self.shrink()?;
         }
 
-        // get the value being popped
-        let old = self.arr.get(index)?;
-        // shift the array over one value, overwriting where `old` was
-        self.arr.shift_from(index+1, -1)?;
+        let old = self.arr.get(self.physical(index))?;
+
+        if index <= self.len / 2 {
+            // closer to the front: shift 0..index forward into the slot we just freed, then advance head
+            for i in (0..index).rev() {
+                let v = self.arr.get(self.physical(i))?;
+                self.arr.set(self.physical(i + 1), v)?;
+            }
+            self.head = (self.head + 1) & (self.cap - 1);
+        } else {
+            // closer to the back: shift index+1..len back to close the gap
+            for i in index + 1..self.len {
+                let v = self.arr.get(self.physical(i))?;
+                self.arr.set(self.physical(i - 1), v)?;
+            }
+        }
         self.len -= 1;
         // return old
         return Ok(old);
     }
 
-    /// Grows the size of the underlying array to the next power of two
-    fn grow(&mut self) -> Result<(), Error> {
-        self.cap = if self.cap.is_power_of_two() { self.cap * 2 } else { self.cap.next_power_of_two() };
-        self.arr.resize(self.cap)?;
+    /// Shrinks the size of the underlying array to the next power of two below
+    fn shrink(&mut self) -> Result<(), Error> {
+        let new_cap = self.cap / 2;
+        if new_cap < 4 { return Ok(()); }
+        self.try_resize_to(new_cap)?;
         Ok(())
     }
 
-    /// Shrinks the size of the underlying array to the next power of two below, returning anything that was dropped off the end
-    fn shrink(&mut self) -> Result<Self, Error> {
-        // get the next power of 2 down
-        let new_size = if self.cap.is_power_of_two() { self.cap / 2 } else { self.cap.next_power_of_two() / 2 };
+    /// Moves everything into a freshly allocated Array<T> of `new_cap` and resets head to 0
+    /// while we're at it. The old contents are split across at most two runs (`head..cap`
+    /// then `0..head`) since head might be in the middle somewhere, so just copy both.
+    fn try_resize_to(&mut self, new_cap: usize) -> Result<(), ReserveError> {
+        let mut new_arr = Array::<T>::try_new(new_cap)?;
 
-        // get the values that'll be chopped off by the shrink
-        let (a, dropped) = match self.arr.clone().split(new_size) {
-            Ok((l, r)) => (l, r),
-            Err(e) => return Err(Error::new(ErrorKind::Other, format!("Unable to shrink List: {}", e)))
-        };
+        let first_run = if self.cap - self.head < self.len { self.cap - self.head } else { self.len };
+        let second_run = self.len - first_run;
 
-        self.arr = a;
-        self.cap = new_size;
+        unsafe {
+            copy_nonoverlapping(self.arr.as_raw_ptr().add(self.head), new_arr.as_mut_raw_ptr(), first_run);
+            copy_nonoverlapping(self.arr.as_raw_ptr(), new_arr.as_mut_raw_ptr().add(first_run), second_run);
+        }
 
-        Ok(dropped.into())
+        self.arr = new_arr;
+        self.head = 0;
+        self.cap = new_cap;
+        Ok(())
     }
 }
 
-// Trait implementations 
+// Trait implementations
 impl<T: Display> Debug for List<T> {
     fn fmt(&self, f: &mut Formatter) -> fmt_Result {
-        write!(f, "List {{\n\tarr: {},\n\tlen: {},\n\tcap: {}\n}};", format!("{:?}", self.arr), self.len, self.cap)
+        write!(f, "List {{\n\tarr: {:?},\n\tlen: {},\n\tcap: {}\n}};", self.arr, self.len, self.cap)
     }
 } impl<T: Display> Display for List<T> {
     fn fmt(&self, f: &mut Formatter) -> fmt_Result {
@@ -224,6 +400,7 @@ impl<T: Display> Debug for List<T> {
         // Clone the underlying array, carry over the other values
         List {
             arr: self.arr.clone(),
+            head: self.head,
             len: self.len,
             cap: self.cap,
         }
@@ -231,12 +408,18 @@ impl<T: Display> Debug for List<T> {
 } impl<T> From<Array<T>> for List<T> {
     fn from(arr: Array<T>) -> Self {
         // grab the size off the array, because Array<T> doesn't impl Copy
-        let l = arr.size();
+        let len = arr.size();
+        // the ring buffer needs a power-of-two capacity, so pad out if the array isn't one
+        let cap = List::<T>::round_cap(len);
+        let mut arr = arr;
+        if cap != len {
+            arr.resize(cap).unwrap();
+        }
         List {
-            // keep the array, use the Array<T>'s size for len and cap
-            arr: arr,
-            len: l,
-            cap: l,
+            arr,
+            head: 0,
+            len,
+            cap,
         }
     }
 } impl<T: Clone> From<Vec<T>> for List<T> {
@@ -250,26 +433,212 @@ impl<T: Display> Debug for List<T> {
 
 pub struct ListIter<T> {
     list: List<T>,
-    cur: usize,
+    front: usize,
+    back: usize,
 } impl<T> ListIter<T> {
     fn new(list: List<T>) -> Self {
+        let back = list.len();
         ListIter {
-            list: list,
-            cur: 0,
+            list,
+            front: 0,
+            back,
         }
     }
 } impl<T> Iterator for ListIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
-        self.cur += 1;
+        if self.front >= self.back { return None; }
+        // this unwrap is safe because front is always < back <= list.len()
+        let v = self.list.get(self.front).unwrap();
+        self.front += 1;
+        Some(v)
+    }
+} impl<T> DoubleEndedIterator for ListIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front >= self.back { return None; }
+        self.back -= 1;
+        // this unwrap is safe for the same reason as in next()
+        Some(self.list.get(self.back).unwrap())
+    }
+} impl<T> ExactSizeIterator for ListIter<T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// The iterator of &T that List::iter hands back. Walks raw pointers into the backing
+/// Array<T> instead of just holding a &List<T>, but still has to do the same circular
+/// head/cap math as physical() to figure out where things actually are
+pub struct Iter<'a, T> {
+    ptr: *const T,
+    head: usize,
+    cap: usize,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a T>,
+} impl<'a, T> Iter<'a, T> {
+    fn physical(&self, logical: usize) -> usize {
+        (self.head + logical) & (self.cap - 1)
+    }
+
+    /// Turns this into an iterator of owned T by just copying the bits out
+    pub fn copied(self) -> Copied<Self> where T: Copy {
+        Copied { inner: self }
+    }
+
+    /// Turns this into an iterator of owned T by cloning each one
+    pub fn cloned(self) -> Cloned<Self> where T: Clone {
+        Cloned { inner: self }
+    }
+} impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front >= self.back { return None; }
+        let p = self.physical(self.front);
+        self.front += 1;
+        Some(unsafe { &*self.ptr.add(p) })
+    }
+} impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front >= self.back { return None; }
+        self.back -= 1;
+        let p = self.physical(self.back);
+        Some(unsafe { &*self.ptr.add(p) })
+    }
+} impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// Same idea as Iter, but for List::iter_mut - raw *mut T pointers instead, so it can
+/// hand out &mut T's as it goes
+pub struct IterMut<'a, T> {
+    ptr: *mut T,
+    head: usize,
+    cap: usize,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a mut T>,
+} impl<'a, T> IterMut<'a, T> {
+    fn physical(&self, logical: usize) -> usize {
+        (self.head + logical) & (self.cap - 1)
+    }
+} impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.front >= self.back { return None; }
+        let p = self.physical(self.front);
+        self.front += 1;
+        Some(unsafe { &mut *self.ptr.add(p) })
+    }
+} impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.front >= self.back { return None; }
+        self.back -= 1;
+        let p = self.physical(self.back);
+        Some(unsafe { &mut *self.ptr.add(p) })
+    }
+} impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// What you get from Iter::copied - just copies T out of each &T as it goes
+pub struct Copied<I> {
+    inner: I,
+} impl<'a, T: Copy + 'a, I: Iterator<Item=&'a T>> Iterator for Copied<I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().copied()
+    }
+} impl<'a, T: Copy + 'a, I: DoubleEndedIterator<Item=&'a T>> DoubleEndedIterator for Copied<I> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back().copied()
+    }
+}
 
-        match self.list.get(self.cur - 1) {
-            // if we get a value, it's legit
-            Ok(n) => Some(n),
-            // otherwise we got out of bounds
-            Err(_) => None
+/// What you get from Iter::cloned - clones T out of each &T as it goes
+pub struct Cloned<I> {
+    inner: I,
+} impl<'a, T: Clone + 'a, I: Iterator<Item=&'a T>> Iterator for Cloned<I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next().cloned()
+    }
+} impl<'a, T: Clone + 'a, I: DoubleEndedIterator<Item=&'a T>> DoubleEndedIterator for Cloned<I> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back().cloned()
+    }
+}
+
+/// What List::chunks gives you - owned List<T> copies, n elements at a time
+pub struct Chunks<'a, T> {
+    list: &'a List<T>,
+    n: usize,
+    pos: usize,
+} impl<'a, T: Clone> Iterator for Chunks<'a, T> {
+    type Item = List<T>;
+
+    fn next(&mut self) -> Option<List<T>> {
+        if self.pos >= self.list.len() { return None; }
+        let end = if self.pos + self.n < self.list.len() { self.pos + self.n } else { self.list.len() };
+
+        let mut chunk = List::with_capacity(end - self.pos).ok()?;
+        for i in self.pos..end {
+            chunk.push_back(self.list.get(i).ok()?).ok()?;
+        }
+        self.pos = end;
+        Some(chunk)
+    }
+}
+
+/// What List::array_chunks gives you - [T; N] arrays, one after another, skipping whatever's
+/// left over at the end if it doesn't make a full N. Call remainder() after to grab that tail
+pub struct ArrayChunks<'a, T, const N: usize> {
+    list: &'a List<T>,
+    pos: usize,
+} impl<'a, T, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<[T; N]> {
+        if self.pos + N > self.list.len() { return None; }
+
+        let mut data: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut initialized = 0;
+        while initialized < N {
+            match self.list.get(self.pos + initialized) {
+                Ok(v) => { data[initialized] = MaybeUninit::new(v); initialized += 1; }
+                Err(_) => break,
+            }
         }
 
+        if initialized == N {
+            self.pos += N;
+            // safety: every slot 0..N was just filled in above
+            Some(unsafe { (&data as *const [MaybeUninit<T>; N] as *const [T; N]).read() })
+        } else {
+            // shouldn't happen given the bounds check up top, but if List::get somehow
+            // fails partway through, at least drop what we did manage to init instead of leaking
+            for slot in data.iter_mut().take(initialized) {
+                unsafe { drop_in_place(slot.as_mut_ptr()); }
+            }
+            None
+        }
     }
-}
\ No newline at end of file
+} impl<'a, T: Clone, const N: usize> ArrayChunks<'a, T, N> {
+    /// Grabs whatever's left that didn't fit in a full [T; N] once iteration stops
+    pub fn remainder(&self) -> Result<List<T>, Error> {
+        let mut rem = List::with_capacity(self.list.len() - self.pos)?;
+        for i in self.pos..self.list.len() {
+            rem.push_back(self.list.get(i)?)?;
+        }
+        Ok(rem)
+    }
+}
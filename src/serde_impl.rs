@@ -0,0 +1,72 @@
+//! serde support for Array<T> and List<T>. Stuck behind the `serde` feature flag so you're
+//! not stuck pulling in serde as a dependency if you don't actually want it.
+
+use {
+    crate::{array::Array, list::List},
+    std::{fmt, marker::PhantomData},
+    serde::{
+        Serialize, Serializer,
+        ser::SerializeSeq,
+        Deserialize, Deserializer,
+        de::{self, Visitor, SeqAccess},
+    },
+};
+
+impl<T: Serialize> Serialize for Array<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.size()))?;
+        for index in 0..self.size() {
+            // has to be get_ref, not get - get reads the value out instead of borrowing it,
+            // which would be real bad here since this only takes &self
+            let value = self.get_ref(index).map_err(|e| serde::ser::Error::custom(e.to_string()))?;
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone> Deserialize<'de> for Array<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // easiest way in: just collect into a Vec first and hand it off to from_iter
+        let buf: Vec<T> = Vec::deserialize(deserializer)?;
+        Array::from_iter(buf.into_iter()).map_err(|e| de::Error::custom(e.to_string()))
+    }
+}
+
+impl<T: Serialize> Serialize for List<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for value in self.iter() {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone> Deserialize<'de> for List<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+struct ListVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de> + Clone> Visitor<'de> for ListVisitor<T> {
+    type Value = List<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // if the format bothers to tell us how big this is, use it, otherwise just start empty
+        let mut list = match seq.size_hint() {
+            Some(n) => List::with_capacity(n).map_err(|e| de::Error::custom(e.to_string()))?,
+            None => List::new().map_err(|e| de::Error::custom(e.to_string()))?,
+        };
+        while let Some(value) = seq.next_element()? {
+            list.push_back(value).map_err(|e| de::Error::custom(e.to_string()))?;
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,186 @@
+use {
+    crate::list::{List, ListIter},
+    std::{
+        io::Error,
+        mem::MaybeUninit,
+        ptr::{read, drop_in_place},
+    },
+};
+
+/// A List<T> that keeps its first N elements inline in `[MaybeUninit<T>; N]` instead of
+/// allocating anything, right up until you try to push past N - then it spills everything
+/// into a real heap-backed List<T> and just forwards to that from then on. Basically free
+/// for small short-lived collections, but still grows without a cap like a normal List<T>.
+pub struct SmallList<T, const N: usize> {
+    repr: Repr<T, N>,
+}
+
+enum Repr<T, const N: usize> {
+    Inline { data: [MaybeUninit<T>; N], len: usize },
+    Spilled(List<T>),
+}
+
+// Public methods
+impl<T, const N: usize> SmallList<T, N> {
+    /// Creates a new, empty SmallList<T, N>. Can't fail like List::new can, since inline
+    /// mode doesn't touch the allocator until you push element N+1
+    pub fn new() -> Self {
+        SmallList {
+            repr: Repr::Inline { data: Self::uninit_array(), len: 0 },
+        }
+    }
+
+    /// Returns the length of the SmallList<T, N>
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { len, .. } => *len,
+            Repr::Spilled(list) => list.len(),
+        }
+    }
+
+    /// Borrows the value at `index` instead of reading it out. The old `get` used to `read`
+    /// it out, which duplicates the value instead of borrowing it - fine once, but double-free
+    /// waiting to happen on a `T` with a real Drop the second you touch that slot again
+    pub fn get_ref(&self, index: usize) -> Result<&T, Error> {
+        match &self.repr {
+            Repr::Inline { data, len } => {
+                if index >= *len { return Err(Error::other("Index out of range")); }
+                Ok(unsafe { &*data[index].as_ptr() })
+            }
+            Repr::Spilled(list) => list.get_ref(index),
+        }
+    }
+
+    /// Gets a clone of the value at `index`
+    pub fn get(&self, index: usize) -> Result<T, Error> where T: Clone {
+        self.get_ref(index).map(|v| v.clone())
+    }
+
+    /// Pushes `value` to the front of the SmallList<T, N>
+    pub fn push_front(&mut self, value: T) -> Result<(), Error> {
+        self.insert(0, value)
+    }
+
+    /// Pushes `value` to the back of the SmallList<T, N>
+    pub fn push_back(&mut self, value: T) -> Result<(), Error> {
+        let len = self.len();
+        self.insert(len, value)
+    }
+
+    /// Pushes `value` to the index `index` in the SmallList<T, N>
+    pub fn push(&mut self, index: usize, value: T) -> Result<(), Error> {
+        self.insert(index, value)
+    }
+}
+
+// Private methods
+impl<T, const N: usize> SmallList<T, N> {
+    fn uninit_array() -> [MaybeUninit<T>; N] {
+        // Safety: an array of MaybeUninit<T> doesn't need its elements initialized
+        unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
+    }
+
+    /// Backend for the push methods
+    /// sticks `value` in at `index`, spilling to a heap-backed List<T> first if inline's full
+    fn insert(&mut self, index: usize, value: T) -> Result<(), Error> {
+        match &mut self.repr {
+            Repr::Inline { data, len } => {
+                if index > *len { return Err(Error::other("Index out of bounds")); }
+                if *len < N {
+                    // shove index..len over by one to open a gap, same deal as List::insert
+                    for i in (index..*len).rev() {
+                        let v = unsafe { read(data[i].as_ptr()) };
+                        data[i + 1] = MaybeUninit::new(v);
+                    }
+                    data[index] = MaybeUninit::new(value);
+                    *len += 1;
+                    return Ok(());
+                }
+            }
+            Repr::Spilled(list) => return list.push(index, value),
+        }
+        // the inline buffer was full: spill to a List<T> and retry there
+        self.spill()?;
+        self.insert(index, value)
+    }
+
+    /// Moves everything inline over to a real heap-backed List<T> and switches to the
+    /// spilled representation. No-op if we've already spilled.
+    fn spill(&mut self) -> Result<(), Error> {
+        if let Repr::Spilled(_) = &self.repr { return Ok(()); }
+
+        // can't just destructure self.repr since SmallList has a Drop impl, so swap in a
+        // dummy empty placeholder first and move the real data out of that instead
+        let placeholder = Repr::Inline { data: Self::uninit_array(), len: 0 };
+        let old = std::mem::replace(&mut self.repr, placeholder);
+
+        if let Repr::Inline { data, len } = old {
+            let mut list = List::with_capacity(len + 1)?;
+            for slot in data.iter().take(len) {
+                let value = unsafe { read(slot.as_ptr()) };
+                list.push_back(value)?;
+            }
+            self.repr = Repr::Spilled(list);
+        }
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Drop for SmallList<T, N> {
+    fn drop(&mut self) {
+        // MaybeUninit<T> doesn't drop its contents on its own, so gotta do the first `len`
+        // slots by hand; Spilled(List<T>) just drops itself like normal, nothing special there
+        if let Repr::Inline { data, len } = &mut self.repr {
+            for slot in data.iter_mut().take(*len) {
+                unsafe { drop_in_place(slot.as_mut_ptr()); }
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallList<T, N> {
+    type Item = T;
+    type IntoIter = SmallListIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // safe because we forget self right after, so its Drop never fires on the repr we just stole
+        let repr = unsafe { read(&self.repr) };
+        std::mem::forget(self);
+        match repr {
+            Repr::Inline { data, len } => SmallListIter::Inline { data, len, cur: 0 },
+            Repr::Spilled(list) => SmallListIter::Spilled(list.into_iter()),
+        }
+    }
+}
+
+pub enum SmallListIter<T, const N: usize> {
+    Inline { data: [MaybeUninit<T>; N], len: usize, cur: usize },
+    Spilled(ListIter<T>),
+}
+
+impl<T, const N: usize> Iterator for SmallListIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self {
+            SmallListIter::Inline { data, len, cur } => {
+                if *cur >= *len { return None; }
+                let v = unsafe { read(data[*cur].as_ptr()) };
+                *cur += 1;
+                Some(v)
+            }
+            SmallListIter::Spilled(iter) => iter.next(),
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SmallListIter<T, N> {
+    fn drop(&mut self) {
+        // same deal as SmallList::drop - gotta drop whatever's left that we never yielded
+        if let SmallListIter::Inline { data, len, cur } = self {
+            for slot in data.iter_mut().take(*len).skip(*cur) {
+                unsafe { drop_in_place(slot.as_mut_ptr()); }
+            }
+        }
+    }
+}